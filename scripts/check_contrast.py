@@ -0,0 +1,282 @@
+import argparse, colorsys, json, re, os, sys
+
+BASE_DIR = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+COLORS_FILE = os.path.join(BASE_DIR, 'colors.md')
+THEMES_DIR = os.path.join(BASE_DIR, 'themes')
+
+# (foreground, background) pairs that Fresh draws as text on top of each other.
+TEXT_PAIRS = [
+    ('editor.fg', 'editor.bg'),
+    ('editor.fg', 'editor.selection_bg'),
+    ('editor.fg', 'editor.current_line_bg'),
+    ('ui.tab_active_fg', 'ui.tab_active_bg'),
+    ('ui.menu_fg', 'ui.menu_bg'),
+    ('ui.menu_active_fg', 'ui.menu_active_bg'),
+    ('ui.menu_dropdown_fg', 'ui.menu_dropdown_bg'),
+    ('ui.menu_highlight_fg', 'ui.menu_highlight_bg'),
+    ('ui.menu_hover_fg', 'ui.menu_hover_bg'),
+    ('ui.status_bar_fg', 'ui.status_bar_bg'),
+    ('ui.prompt_fg', 'ui.prompt_bg'),
+    ('ui.prompt_selection_fg', 'ui.prompt_selection_bg'),
+    ('ui.popup_text_fg', 'ui.popup_bg'),
+    ('ui.popup_selection_fg', 'ui.popup_selection_bg'),
+    ('ui.help_fg', 'ui.help_bg'),
+    ('ui.help_key_fg', 'ui.help_bg'),
+    ('ui.help_indicator_fg', 'ui.help_indicator_bg'),
+    ('ui.status_warning_indicator_fg', 'ui.status_warning_indicator_bg'),
+    ('ui.status_error_indicator_fg', 'ui.status_error_indicator_bg'),
+    ('ui.status_warning_indicator_hover_fg', 'ui.status_warning_indicator_hover_bg'),
+    ('ui.status_error_indicator_hover_fg', 'ui.status_error_indicator_hover_bg'),
+    ('search.match_fg', 'search.match_bg'),
+    ('diagnostic.error_fg', 'diagnostic.error_bg'),
+    ('diagnostic.warning_fg', 'diagnostic.warning_bg'),
+    ('diagnostic.info_fg', 'diagnostic.info_bg'),
+    ('syntax.keyword', 'editor.bg'),
+    ('syntax.string', 'editor.bg'),
+    ('syntax.function', 'editor.bg'),
+    ('syntax.type', 'editor.bg'),
+    ('syntax.variable', 'editor.bg'),
+    ('syntax.constant', 'editor.bg'),
+    ('syntax.operator', 'editor.bg'),
+]
+
+# Secondary text that is meant to recede; checked against a lower threshold.
+DIM_PAIRS = [
+    ('editor.line_number_fg', 'editor.line_number_bg'),
+    ('ui.tab_inactive_fg', 'ui.tab_inactive_bg'),
+    ('ui.menu_disabled_fg', 'ui.menu_disabled_bg'),
+    ('diagnostic.hint_fg', 'diagnostic.hint_bg'),
+    ('syntax.comment', 'editor.bg'),
+]
+
+# Main text colors define a theme's look; --fix reports these instead of
+# swapping them, since the background is usually the outlier.
+PROTECTED_FOREGROUNDS = {
+    'editor.fg',
+    'ui.menu_fg',
+    'ui.menu_dropdown_fg',
+    'ui.status_bar_fg',
+    'ui.prompt_fg',
+    'ui.popup_text_fg',
+    'ui.help_fg',
+    'syntax.variable',
+}
+
+# Fields that must stay one color when they start out equal; --fix moves them
+# together so a hover state keeps matching its resting state.
+LINKED_FOREGROUNDS = [
+    ('ui.status_warning_indicator_fg', 'ui.status_warning_indicator_hover_fg'),
+    ('ui.status_error_indicator_fg', 'ui.status_error_indicator_hover_fg'),
+]
+
+# Foregrounds that tell the user which kind of message they are looking at;
+# --fix never makes two of them the same color.
+ROLE_FOREGROUNDS = [
+    ('diagnostic.error_fg', 'diagnostic.warning_fg', 'diagnostic.info_fg', 'diagnostic.hint_fg'),
+    ('ui.status_warning_indicator_fg', 'ui.status_error_indicator_fg'),
+]
+
+# Colors whose channels differ by less than GREY_CHROMA count as grey, and a
+# replacement may drift at most HUE_TOLERANCE degrees from the original hue.
+GREY_CHROMA = 0.15
+HUE_TOLERANCE = 30
+
+
+def build_checks(min_ratio=4.5, min_dim_ratio=3.0):
+    checks = [(fg, bg, min_ratio) for fg, bg in TEXT_PAIRS]
+    checks += [(fg, bg, min_dim_ratio) for fg, bg in DIM_PAIRS]
+    return checks
+
+
+def hex_to_rgb(hex_str):
+    hex_str = hex_str.lstrip('#')
+    return tuple(int(hex_str[i:i + 2], 16) for i in (0, 2, 4))
+
+
+def rgb_to_hex(rgb):
+    return f'#{rgb[0]:02x}{rgb[1]:02x}{rgb[2]:02x}'
+
+
+def parse_palettes(md_path):
+    with open(md_path) as f:
+        content = f.read()
+    sections = re.split(r'<h2[^>]*>([^<]+)</h2>', content)
+    palettes = {}
+    for i in range(1, len(sections), 2):
+        name = sections[i].strip()
+        block = sections[i + 1]
+        json_match = re.search(r'\{[^}]+\}', block, re.DOTALL)
+        if json_match:
+            palettes[name.lower()] = json.loads(json_match.group())
+    return palettes
+
+
+def relative_luminance(rgb):
+    channels = []
+    for v in rgb:
+        c = v / 255
+        channels.append(c / 12.92 if c <= 0.03928 else ((c + 0.055) / 1.055) ** 2.4)
+    return 0.2126 * channels[0] + 0.7152 * channels[1] + 0.0722 * channels[2]
+
+
+def contrast_ratio(fg, bg):
+    l1, l2 = relative_luminance(fg), relative_luminance(bg)
+    lighter, darker = max(l1, l2), min(l1, l2)
+    return (lighter + 0.05) / (darker + 0.05)
+
+
+def get_field(theme, path):
+    section, field = path.split('.', 1)
+    value = theme.get(section, {}).get(field)
+    if isinstance(value, list) and len(value) == 3:
+        return tuple(value)
+    return None
+
+
+def set_field(theme, path, rgb):
+    section, field = path.split('.', 1)
+    theme[section][field] = list(rgb)
+
+
+def to_hls(rgb):
+    return colorsys.rgb_to_hls(*(v / 255 for v in rgb))
+
+
+def is_grey(rgb):
+    return (max(rgb) - min(rgb)) / 255 < GREY_CHROMA
+
+
+def hue_distance(rgb, other):
+    if is_grey(rgb) or is_grey(other):
+        return 0 if is_grey(rgb) and is_grey(other) else None
+    distance = abs(to_hls(rgb)[0] - to_hls(other)[0]) * 360
+    distance = min(distance, 360 - distance)
+    return distance if distance <= HUE_TOLERANCE else None
+
+
+def closest_passing(fg, constraints, candidates, excluded=()):
+    # Only colors of the same hue (or greys for a grey) are considered, ranked
+    # by hue distance first and lightness distance second, so a fix reads as a
+    # lighter or darker version of the original.
+    ranked = []
+    for c in candidates:
+        if c in excluded or hue_distance(fg, c) is None:
+            continue
+        if all(contrast_ratio(c, bg) >= minimum for bg, minimum in constraints):
+            ranked.append((hue_distance(fg, c), abs(to_hls(c)[1] - to_hls(fg)[1]), c))
+    if not ranked:
+        return None
+    return min(ranked)[2]
+
+
+def find_failures(theme, checks):
+    failures = []
+    for fg_path, bg_path, minimum in checks:
+        fg, bg = get_field(theme, fg_path), get_field(theme, bg_path)
+        if fg is None or bg is None:
+            continue
+        ratio = contrast_ratio(fg, bg)
+        if ratio < minimum:
+            failures.append((fg_path, fg, bg_path, bg, ratio, minimum))
+    return failures
+
+
+def linked_fields(theme, fg_path):
+    for group in LINKED_FOREGROUNDS:
+        if fg_path in group:
+            return [path for path in group if get_field(theme, path) == get_field(theme, fg_path)]
+    return [fg_path]
+
+
+def role_colors(theme, fields):
+    colors = set()
+    for group in ROLE_FOREGROUNDS:
+        if any(path in group for path in fields):
+            colors |= {get_field(theme, path) for path in group if path not in fields}
+    # Dim text has to stay distinguishable from the main text it sits next to.
+    if any(path == fg for path in fields for fg, _ in DIM_PAIRS):
+        colors |= {get_field(theme, path) for path in PROTECTED_FOREGROUNDS}
+    colors.discard(None)
+    return colors
+
+
+def fix_theme(theme, checks, palette_rgbs):
+    # A foreground is only replaced by a color that passes every pair it is
+    # drawn in, so fixing one pair can never break another.
+    adjusted, skipped = [], []
+    done = set()
+    for fg_path in dict.fromkeys(f[0] for f in find_failures(theme, checks)):
+        if fg_path in done:
+            continue
+        fields = linked_fields(theme, fg_path)
+        done.update(fields)
+        if any(path in PROTECTED_FOREGROUNDS for path in fields):
+            skipped.append((fg_path, 'main text color, adjust the background instead'))
+            continue
+        fg = get_field(theme, fg_path)
+        constraints = [(get_field(theme, bg_path), minimum)
+                       for path, bg_path, minimum in checks
+                       if path in fields and get_field(theme, bg_path) is not None]
+        replacement = closest_passing(fg, constraints, palette_rgbs, role_colors(theme, fields))
+        if replacement is None:
+            if closest_passing(fg, constraints, palette_rgbs) is not None:
+                skipped.append((fg_path, 'every passing color is already used by another role'))
+            else:
+                skipped.append((fg_path, 'no palette color of the same hue passes every pair'))
+            continue
+        for path in fields:
+            set_field(theme, path, replacement)
+            adjusted.append((path, fg, replacement))
+    return adjusted, skipped
+
+
+def main():
+    parser = argparse.ArgumentParser(description='Check WCAG contrast of foreground/background pairs in themes/*.json.')
+    parser.add_argument('--min-ratio', type=float, default=4.5, help='minimum ratio for regular text (default: 4.5)')
+    parser.add_argument('--min-dim-ratio', type=float, default=3.0, help='minimum ratio for dim text such as line numbers and comments (default: 3.0)')
+    parser.add_argument('--fix', action='store_true', help='replace failing foregrounds with a lighter or darker palette color of the same hue that passes every pair they are used in')
+    parser.add_argument('--strict', action='store_true', help='exit with status 1 when any pair stays below its minimum')
+    args = parser.parse_args()
+
+    palettes = parse_palettes(COLORS_FILE)
+    checks = build_checks(args.min_ratio, args.min_dim_ratio)
+    exit_code = 0
+
+    for fname in sorted(os.listdir(THEMES_DIR)):
+        if not fname.endswith('.json'):
+            continue
+        name = fname.replace('.json', '')
+        filepath = os.path.join(THEMES_DIR, fname)
+        with open(filepath) as f:
+            theme = json.load(f)
+
+        skipped = []
+        if args.fix and name not in palettes:
+            print(f'{name}: SKIPPED (no palette in colors.md)')
+        elif args.fix:
+            palette_rgbs = sorted({hex_to_rgb(palettes[name][f'color{i}']) for i in range(16)})
+            adjusted, skipped = fix_theme(theme, checks, palette_rgbs)
+            if adjusted:
+                with open(filepath, 'w') as f:
+                    json.dump(theme, f, indent=2)
+                    f.write('\n')
+            for fg_path, old, new in adjusted:
+                print(f'{name}: adjusted {fg_path} {rgb_to_hex(old)} -> {rgb_to_hex(new)}')
+
+        failures = find_failures(theme, checks)
+        if failures:
+            print(f'{name}: WARN ({len(failures)} pair(s) below minimum contrast)')
+            for fg_path, fg, bg_path, bg, ratio, minimum in failures:
+                print(f'  {fg_path} {rgb_to_hex(fg)} on {bg_path} {rgb_to_hex(bg)}: {ratio:.2f} < {minimum}')
+            for fg_path, reason in skipped:
+                print(f'  not adjusted: {fg_path} ({reason})')
+            if args.strict:
+                exit_code = 1
+        else:
+            print(f'{name}: OK')
+
+    sys.exit(exit_code)
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,100 @@
+import unittest
+
+from check_contrast import build_checks, closest_passing, contrast_ratio, find_failures, fix_theme, get_field
+
+DIAGNOSTICS = ('diagnostic.error_fg', 'diagnostic.warning_fg', 'diagnostic.info_fg')
+
+
+def failing_pairs(theme):
+    return {(fg, bg) for fg, _, bg, _, _, _ in find_failures(theme, build_checks())}
+
+
+class ContrastTest(unittest.TestCase):
+    def test_black_on_white_is_maximum_contrast(self):
+        self.assertAlmostEqual(contrast_ratio((0, 0, 0), (255, 255, 255)), 21.0)
+
+    def test_grey_on_grey_fails(self):
+        self.assertLess(contrast_ratio((128, 128, 128), (110, 110, 110)), 4.5)
+
+    def test_closest_passing_reaches_minimum(self):
+        bg = (110, 110, 110)
+        candidates = [(0, 0, 0), (130, 130, 130), (255, 255, 255)]
+        replacement = closest_passing((128, 128, 128), [(bg, 4.5)], candidates)
+        self.assertIsNotNone(replacement)
+        self.assertGreaterEqual(contrast_ratio(replacement, bg), 4.5)
+
+    def test_closest_passing_respects_every_constraint(self):
+        # White passes on the dark background only, black on the light one only.
+        constraints = [((10, 10, 10), 4.5), ((250, 250, 250), 4.5)]
+        self.assertIsNone(closest_passing((128, 128, 128), constraints, [(0, 0, 0), (255, 255, 255)]))
+
+    def test_closest_passing_keeps_the_hue(self):
+        # White and light green pass too, but the light red is the same hue.
+        candidates = [(255, 255, 255), (150, 255, 150), (255, 140, 140)]
+        replacement = closest_passing((150, 20, 20), [((10, 10, 10), 4.5)], candidates)
+        self.assertEqual(replacement, (255, 140, 140))
+
+    def test_fix_skips_linked_foreground_that_would_break_its_other_pair(self):
+        # White would fix the dark resting background and break the light hover one.
+        theme = {'ui': {
+            'status_error_indicator_fg': [40, 40, 40],
+            'status_error_indicator_bg': [10, 10, 10],
+            'status_error_indicator_hover_fg': [40, 40, 40],
+            'status_error_indicator_hover_bg': [250, 250, 250],
+        }}
+        before = failing_pairs(theme)
+        adjusted, skipped = fix_theme(theme, build_checks(), [(0, 0, 0), (40, 40, 40), (255, 255, 255)])
+        self.assertEqual(adjusted, [])
+        self.assertEqual([path for path, _ in skipped], ['ui.status_error_indicator_fg'])
+        self.assertEqual(failing_pairs(theme), before)
+
+    def test_fix_moves_linked_foregrounds_together(self):
+        theme = {'ui': {
+            'status_error_indicator_fg': [120, 120, 120],
+            'status_error_indicator_bg': [120, 120, 120],
+            'status_error_indicator_hover_fg': [120, 120, 120],
+            'status_error_indicator_hover_bg': [250, 250, 250],
+        }}
+        adjusted, _ = fix_theme(theme, build_checks(), [(0, 0, 0), (40, 40, 40), (255, 255, 255)])
+        self.assertEqual([path for path, _, _ in adjusted],
+                         ['ui.status_error_indicator_fg', 'ui.status_error_indicator_hover_fg'])
+        self.assertEqual(get_field(theme, 'ui.status_error_indicator_fg'),
+                         get_field(theme, 'ui.status_error_indicator_hover_fg'))
+        self.assertEqual(failing_pairs(theme), set())
+
+    def test_fix_keeps_diagnostics_distinct(self):
+        dark = [10, 10, 10]
+        theme = {'diagnostic': {
+            'error_fg': [120, 20, 20], 'error_bg': dark,
+            'warning_fg': [110, 100, 10], 'warning_bg': dark,
+            'info_fg': [20, 40, 120], 'info_bg': dark,
+        }}
+        palette = [(10, 10, 10), (255, 255, 255), (255, 110, 110), (250, 230, 90), (110, 170, 255)]
+        fix_theme(theme, build_checks(), palette)
+        colors = [get_field(theme, path) for path in DIAGNOSTICS]
+        self.assertEqual(len(set(colors)), 3)
+        self.assertEqual(failing_pairs(theme), set())
+
+    def test_fix_skips_diagnostic_whose_only_passing_color_is_taken(self):
+        dark = [10, 10, 10]
+        theme = {'diagnostic': {
+            'error_fg': [255, 110, 110], 'error_bg': dark,
+            'warning_fg': [60, 60, 60], 'warning_bg': dark,
+            'info_fg': [70, 70, 70], 'info_bg': dark,
+        }}
+        adjusted, skipped = fix_theme(theme, build_checks(), [(10, 10, 10), (255, 110, 110), (240, 240, 240)])
+        self.assertEqual([path for path, _, _ in adjusted], ['diagnostic.warning_fg'])
+        self.assertEqual([path for path, _ in skipped], ['diagnostic.info_fg'])
+        colors = [get_field(theme, path) for path in DIAGNOSTICS]
+        self.assertEqual(len(set(colors)), 3)
+
+    def test_fix_leaves_main_text_foreground_alone(self):
+        theme = {'editor': {'bg': [10, 10, 10], 'fg': [30, 30, 30]}}
+        adjusted, skipped = fix_theme(theme, build_checks(), [(10, 10, 10), (240, 240, 240)])
+        self.assertEqual(adjusted, [])
+        self.assertEqual([path for path, _ in skipped], ['editor.fg'])
+        self.assertEqual(get_field(theme, 'editor.fg'), (30, 30, 30))
+
+
+if __name__ == '__main__':
+    unittest.main()